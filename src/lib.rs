@@ -3,13 +3,32 @@
 //! > Sven Woop, Carsten Benthin, and Ingo Wald. "Watertight ray/triangle intersection."
 //! > Journal of Computer Graphics Techniques (JCGT) 2.1 (2013): 65-82.
 //!
-//! Does not perform backface culling.
+//! By default does not perform backface culling; pass a `CullMode` to
+//! `RayData::new_culled` to get single-sided behavior.
 #![allow(non_snake_case)]
 // Variable names from the paper (appendix A) are not snake_case
 extern crate cgmath;
+extern crate wide;
+// `wide` backs RayData::<f32>::intersect_packet_simd's f32x4 lanes, staying
+// on stable instead of requiring the nightly `portable_simd` feature.
 
 // use cgmath::num_traits::Signed;
-use cgmath::{BaseFloat, Vector3};
+use cgmath::{BaseFloat, InnerSpace, Vector3};
+
+/// Which side of a triangle, if any, `RayData::intersect` should reject.
+///
+/// Front-facing is the side from which the triangle's vertices appear in
+/// counter-clockwise order, matching the winding convention used for
+/// backface culling in most renderers.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CullMode {
+    /// Hit both sides of the triangle (the default).
+    None,
+    /// Reject hits on the back face.
+    Back,
+    /// Reject hits on the front face.
+    Front,
+}
 
 /// Precomputed data depending only on the ray.
 #[derive(Clone, Debug)]
@@ -21,19 +40,34 @@ pub struct RayData<S> {
     sy: S,
     sz: S,
     org: Vector3<S>,
+    dir: Vector3<S>,
+    cull: CullMode,
 }
 
 impl<S> RayData<S>
 where
-    S: BaseFloat,
+    S: BaseFloat + WidenPrecision,
 {
     /// Pre-compute the transformation that is applied to all triangles.
+    ///
+    /// Hits on either side of a triangle are returned; use `new_culled` for
+    /// single-sided behavior.
     pub fn new(org: Vector3<S>, dir: Vector3<S>) -> RayData<S> {
-        // The paper swaps kx and ky if dir[kz] is negative, to preserve winding order.
-        // But winding order is only relevant for backface culling, which we don't perform.
+        RayData::new_culled(org, dir, CullMode::None)
+    }
+
+    /// Like `new`, but reject hits on the side of the triangle given by `cull`.
+    pub fn new_culled(org: Vector3<S>, dir: Vector3<S>, cull: CullMode) -> RayData<S> {
         let kz = max_dim(dir);
-        let kx = (kz + 1) % 3;
-        let ky = (kz + 2) % 3;
+        let mut kx = (kz + 1) % 3;
+        let mut ky = (kz + 2) % 3;
+
+        // The paper swaps kx and ky if dir[kz] is negative, to preserve
+        // winding order, so that the sign of det/u/v/w reliably encodes
+        // front- vs. back-facing for CullMode::Back/Front.
+        if dir[kz] < S::zero() {
+            std::mem::swap(&mut kx, &mut ky);
+        }
 
         // S::div(1.0, dir[kz]);
 
@@ -45,9 +79,116 @@ where
             sy: dir[ky] / dir[kz],
             sz: S::one() / dir[kz],
             org: org,
+            dir: dir,
+            cull: cull,
         }
     }
 
+    /// Intersect this ray against several triangles at once, one at a time.
+    ///
+    /// `out` must be the same length as `tris`. This is the scalar reference
+    /// path against which `RayData::<f32>::intersect_packet_simd` is tested;
+    /// use that instead when `S = f32` and the lane width matters.
+    pub fn intersect_packet(&self, tris: &[[Vector3<S>; 3]], out: &mut [Option<Intersection<S>>]) {
+        assert_eq!(tris.len(), out.len());
+        for (tri, slot) in tris.iter().zip(out.iter_mut()) {
+            *slot = self.intersect(tri[0], tri[1], tri[2]);
+        }
+    }
+
+    /// Perform the intersection calculation, rejecting hits whose parametric
+    /// distance `t` falls outside `[tmin, tmax]`.
+    ///
+    /// This mirrors Cycles/Embree's `ray_t` bound: a BVH traversal can pass
+    /// the current ray segment in and keep shrinking `tmax` to the closest
+    /// hit found so far, so farther triangles are rejected here instead of
+    /// being compared against the best hit by hand afterwards.
+    pub fn intersect_clamped(
+        &self,
+        a: Vector3<S>,
+        b: Vector3<S>,
+        c: Vector3<S>,
+        tmin: S,
+        tmax: S,
+    ) -> Option<Intersection<S>> {
+        match self.intersect(a, b, c) {
+            Some(hit) if hit.t >= tmin && hit.t <= tmax => Some(hit),
+            _ => None,
+        }
+    }
+
+    /// Intersect this ray against a triangle whose vertices move linearly
+    /// from a shutter-open pose `pose0` to a shutter-close pose `pose1`,
+    /// sampled at `time` in `[0, 1]`.
+    ///
+    /// Lerps the vertices, then runs them through `intersect`, reusing the
+    /// per-ray transform precomputed in `new`.
+    pub fn intersect_motion(
+        &self,
+        pose0: [Vector3<S>; 3],
+        pose1: [Vector3<S>; 3],
+        time: S,
+    ) -> Option<Intersection<S>> {
+        let a = pose0[0] + (pose1[0] - pose0[0]) * time;
+        let b = pose0[1] + (pose1[1] - pose0[1]) * time;
+        let c = pose0[2] + (pose1[2] - pose0[2]) * time;
+        self.intersect(a, b, c)
+    }
+
+    /// Intersect this ray against a sphere, returning the nearest positive
+    /// parametric distance `t`.
+    ///
+    /// `self.dir` need not be unit length (unlike most sphere intersectors,
+    /// which assume it is): `t` is solved for directly from the quadratic
+    /// `|org + t*dir - center|^2 = radius^2` instead of using the geometric
+    /// shortcut, which only gives the right `t` for a unit direction.
+    pub fn intersect_sphere(&self, center: Vector3<S>, radius: S) -> Option<S> {
+        let two = S::one() + S::one();
+        let four = two * two;
+
+        let d = self.org - center;
+        let a = self.dir.dot(self.dir);
+        let b = two * self.dir.dot(d);
+        let c = d.dot(d) - radius * radius;
+
+        let disc = b * b - four * a * c;
+        if disc < S::zero() {
+            return None;
+        }
+        let sqrt_disc = disc.sqrt();
+        let two_a = two * a;
+        let t0 = (-b - sqrt_disc) / two_a;
+        if t0 >= S::zero() {
+            return Some(t0);
+        }
+        let t1 = (-b + sqrt_disc) / two_a;
+        if t1 >= S::zero() {
+            return Some(t1);
+        }
+        None
+    }
+
+    /// Intersect this ray against a disk, returning the parametric distance
+    /// `t` at which it is hit.
+    ///
+    /// Intersects the ray with the disk's plane, `(P - center) . normal == 0`,
+    /// then tests the hit point's distance to `center` against `radius`.
+    pub fn intersect_disk(&self, center: Vector3<S>, normal: Vector3<S>, radius: S) -> Option<S> {
+        let denom = self.dir.dot(normal);
+        if denom == S::zero() {
+            return None;
+        }
+        let t = (center - self.org).dot(normal) / denom;
+        if t < S::zero() {
+            return None;
+        }
+        let p = self.org + self.dir * t;
+        if (p - center).dot(p - center) > radius * radius {
+            return None;
+        }
+        Some(t)
+    }
+
     /// Perform the intersection calculation.
     pub fn intersect(
         &self,
@@ -70,15 +211,26 @@ where
         let mut w = bx * ay - by * ax;
 
         if u == S::zero() || v == S::zero() || w == S::zero() {
+            // Recompute the borderline edge functions at higher precision so
+            // that cancellation in S doesn't flip a sign at a shared edge
+            // between adjacent triangles.
+            let (ax, ay, bx, by, cx, cy) = (
+                ax.widen(),
+                ay.widen(),
+                bx.widen(),
+                by.widen(),
+                cx.widen(),
+                cy.widen(),
+            );
             let cxby = cx * by;
             let cybx = cy * bx;
-            u = cxby - cybx;
+            u = S::narrow(cxby - cybx);
             let axcy = ax * cy;
             let aycx = ay * cx;
-            v = axcy - aycx;
+            v = S::narrow(axcy - aycx);
             let bxay = bx * ay;
             let byax = by * ax;
-            w = bxay - byax;
+            w = S::narrow(bxay - byax);
         }
 
         if (u < S::zero() || v < S::zero() || w < S::zero())
@@ -91,6 +243,12 @@ where
         if det == S::zero() {
             return None;
         }
+        match self.cull {
+            CullMode::None => {}
+            CullMode::Back if det < S::zero() => return None,
+            CullMode::Front if det > S::zero() => return None,
+            CullMode::Back | CullMode::Front => {}
+        }
 
         let az = sz * a[kz];
         let bz = sz * b[kz];
@@ -107,6 +265,137 @@ where
     }
 }
 
+impl RayData<f32> {
+    /// Intersect this ray against several triangles at once, 4 lanes wide.
+    ///
+    /// Evaluates the same shear/edge-test transform as `intersect`, but with
+    /// `wide::f32x4` so the per-ray precompute in `new` is amortized over 4
+    /// triangles per vector instruction instead of per function call (the
+    /// win BVH leaf traversal is after). `out` must be the same length as
+    /// `tris`; any remainder past the last full group of 4 falls back to
+    /// `intersect_packet`'s scalar loop.
+    pub fn intersect_packet_simd(
+        &self,
+        tris: &[[Vector3<f32>; 3]],
+        out: &mut [Option<Intersection<f32>>],
+    ) {
+        use wide::{f32x4, CmpEq, CmpGt, CmpLt};
+
+        assert_eq!(tris.len(), out.len());
+        let (sx, sy, sz, org) = (self.sx, self.sy, self.sz, self.org);
+        let (kx, ky, kz) = (self.kx, self.ky, self.kz);
+        let zero = f32x4::splat(0.0);
+
+        let lanes = tris.len() / 4;
+        for lane_group in 0..lanes {
+            let base = lane_group * 4;
+            let mut ax = [0.0f32; 4];
+            let mut ay = [0.0f32; 4];
+            let mut bx = [0.0f32; 4];
+            let mut by = [0.0f32; 4];
+            let mut cx = [0.0f32; 4];
+            let mut cy = [0.0f32; 4];
+            let mut az = [0.0f32; 4];
+            let mut bz = [0.0f32; 4];
+            let mut cz = [0.0f32; 4];
+            for i in 0..4 {
+                let [a, b, c] = tris[base + i];
+                let (a, b, c) = (a - org, b - org, c - org);
+                ax[i] = a[kx] - sx * a[kz];
+                ay[i] = a[ky] - sy * a[kz];
+                bx[i] = b[kx] - sx * b[kz];
+                by[i] = b[ky] - sy * b[kz];
+                cx[i] = c[kx] - sx * c[kz];
+                cy[i] = c[ky] - sy * c[kz];
+                az[i] = sz * a[kz];
+                bz[i] = sz * b[kz];
+                cz[i] = sz * c[kz];
+            }
+            let (ax, ay, bx, by, cx, cy) = (
+                f32x4::new(ax),
+                f32x4::new(ay),
+                f32x4::new(bx),
+                f32x4::new(by),
+                f32x4::new(cx),
+                f32x4::new(cy),
+            );
+            let (az, bz, cz) = (f32x4::new(az), f32x4::new(bz), f32x4::new(cz));
+
+            let mut u = cx * by - cy * bx;
+            let mut v = ax * cy - ay * cx;
+            let mut w = bx * ay - by * ax;
+
+            let degenerate_mask = u.cmp_eq(zero) | v.cmp_eq(zero) | w.cmp_eq(zero);
+            if degenerate_mask.move_mask() != 0 {
+                // Fall back to intersect's f64-widened recompute, lane by lane.
+                let (mut ua, mut va, mut wa) = (u.to_array(), v.to_array(), w.to_array());
+                let degenerate_bits = degenerate_mask.move_mask();
+                let (axa, aya, bxa, bya, cxa, cya) = (
+                    ax.to_array(),
+                    ay.to_array(),
+                    bx.to_array(),
+                    by.to_array(),
+                    cx.to_array(),
+                    cy.to_array(),
+                );
+                for i in 0..4 {
+                    if degenerate_bits & (1 << i) == 0 {
+                        continue;
+                    }
+                    let (ax, ay, bx, by, cx, cy) = (
+                        axa[i] as f64,
+                        aya[i] as f64,
+                        bxa[i] as f64,
+                        bya[i] as f64,
+                        cxa[i] as f64,
+                        cya[i] as f64,
+                    );
+                    ua[i] = (cx * by - cy * bx) as f32;
+                    va[i] = (ax * cy - ay * cx) as f32;
+                    wa[i] = (bx * ay - by * ax) as f32;
+                }
+                u = f32x4::new(ua);
+                v = f32x4::new(va);
+                w = f32x4::new(wa);
+            }
+
+            let mixed_sign = (u.cmp_lt(zero) | v.cmp_lt(zero) | w.cmp_lt(zero))
+                & (u.cmp_gt(zero) | v.cmp_gt(zero) | w.cmp_gt(zero));
+            let mut hit = !mixed_sign;
+
+            let det = u + v + w;
+            hit &= !det.cmp_eq(zero);
+            match self.cull {
+                CullMode::None => {}
+                CullMode::Back => hit &= !det.cmp_lt(zero),
+                CullMode::Front => hit &= !det.cmp_gt(zero),
+            }
+
+            let rcp_det = f32x4::splat(1.0) / det;
+            let t = ((u * az + v * bz + w * cz) * rcp_det).to_array();
+            let u = (u * rcp_det).to_array();
+            let v = (v * rcp_det).to_array();
+            let w = (w * rcp_det).to_array();
+            let hit_bits = hit.move_mask();
+
+            for i in 0..4 {
+                out[base + i] = if hit_bits & (1 << i) != 0 {
+                    Some(Intersection {
+                        t: t[i],
+                        u: u[i],
+                        v: v[i],
+                        w: w[i],
+                    })
+                } else {
+                    None
+                };
+            }
+        }
+
+        self.intersect_packet(&tris[lanes * 4..], &mut out[lanes * 4..]);
+    }
+}
+
 /// Geometric information about a ray-triangle intersection.
 pub struct Intersection<S> {
     /// Parametric distance from the ray origin to the intersection.
@@ -119,6 +408,44 @@ pub struct Intersection<S> {
     pub w: S,
 }
 
+/// Promotes a float type to a higher-precision type for recomputing the
+/// borderline edge functions in `RayData::intersect`, so that the
+/// watertightness guarantee holds even when `S` is `f32`.
+pub trait WidenPrecision: BaseFloat {
+    /// The higher-precision type to recompute edge functions in.
+    type Wide: BaseFloat;
+
+    /// Promote `self` to `Wide`.
+    fn widen(self) -> Self::Wide;
+
+    /// Round a `Wide` value back down to `Self`.
+    fn narrow(wide: Self::Wide) -> Self;
+}
+
+impl WidenPrecision for f32 {
+    type Wide = f64;
+
+    fn widen(self) -> f64 {
+        self as f64
+    }
+
+    fn narrow(wide: f64) -> f32 {
+        wide as f32
+    }
+}
+
+impl WidenPrecision for f64 {
+    type Wide = f64;
+
+    fn widen(self) -> f64 {
+        self
+    }
+
+    fn narrow(wide: f64) -> f64 {
+        wide
+    }
+}
+
 fn max_dim<S>(v: Vector3<S>) -> usize
 where
     S: BaseFloat,
@@ -138,3 +465,242 @@ where
         2
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intersect_packet_matches_scalar_intersect() {
+        let ray = RayData::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0));
+        let tris = [
+            [
+                Vector3::new(-1.0, -1.0, 5.0),
+                Vector3::new(1.0, -1.0, 5.0),
+                Vector3::new(0.0, 1.0, 5.0),
+            ],
+            [
+                Vector3::new(10.0, 10.0, 5.0),
+                Vector3::new(11.0, 10.0, 5.0),
+                Vector3::new(10.0, 11.0, 5.0),
+            ],
+            // Degenerate lane: the ray passes exactly through vertex `a`,
+            // so v == w == 0 and the f64 recompute in `intersect` fires.
+            [
+                Vector3::new(0.0, 0.0, 5.0),
+                Vector3::new(1.0, -1.0, 5.0),
+                Vector3::new(-1.0, -1.0, 5.0),
+            ],
+        ];
+        let mut packet = [None, None, None];
+        ray.intersect_packet(&tris, &mut packet);
+
+        for (tri, got) in tris.iter().zip(packet.iter()) {
+            let want = ray.intersect(tri[0], tri[1], tri[2]);
+            assert_eq!(want.as_ref().map(|h| h.t), got.as_ref().map(|h| h.t));
+        }
+        assert!(packet[0].is_some());
+        assert!(packet[1].is_none());
+        assert!(packet[2].is_some());
+    }
+
+    #[test]
+    fn intersect_packet_simd_matches_intersect_packet() {
+        let ray: RayData<f32> =
+            RayData::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0));
+        let tris = [
+            [
+                Vector3::new(-1.0, -1.0, 5.0),
+                Vector3::new(1.0, -1.0, 5.0),
+                Vector3::new(0.0, 1.0, 5.0),
+            ],
+            [
+                Vector3::new(10.0, 10.0, 5.0),
+                Vector3::new(11.0, 10.0, 5.0),
+                Vector3::new(10.0, 11.0, 5.0),
+            ],
+            // Same degenerate lane as above, now inside a full SIMD group.
+            [
+                Vector3::new(0.0, 0.0, 5.0),
+                Vector3::new(1.0, -1.0, 5.0),
+                Vector3::new(-1.0, -1.0, 5.0),
+            ],
+            [
+                Vector3::new(-2.0, -2.0, 8.0),
+                Vector3::new(2.0, -2.0, 8.0),
+                Vector3::new(0.0, 2.0, 8.0),
+            ],
+            // Remainder lane past the last full group of 4.
+            [
+                Vector3::new(20.0, 20.0, 5.0),
+                Vector3::new(21.0, 20.0, 5.0),
+                Vector3::new(20.0, 21.0, 5.0),
+            ],
+        ];
+        let mut scalar = [None, None, None, None, None];
+        let mut simd = [None, None, None, None, None];
+        ray.intersect_packet(&tris, &mut scalar);
+        ray.intersect_packet_simd(&tris, &mut simd);
+
+        for (want, got) in scalar.iter().zip(simd.iter()) {
+            assert_eq!(want.as_ref().map(|h| h.t), got.as_ref().map(|h| h.t));
+            assert_eq!(want.as_ref().map(|h| h.u), got.as_ref().map(|h| h.u));
+            assert_eq!(want.as_ref().map(|h| h.v), got.as_ref().map(|h| h.v));
+            assert_eq!(want.as_ref().map(|h| h.w), got.as_ref().map(|h| h.w));
+        }
+    }
+
+    #[test]
+    fn borderline_edge_recompute_avoids_false_positive() {
+        // `b` and `c` sit almost exactly opposite each other across the
+        // ray origin, so the naive (un-widened) `u = cx*by - cy*bx` for
+        // this triangle rounds to exactly 0.0 in f32, while the true
+        // value, only recoverable by widening to f64 before subtracting,
+        // is -594940.75. `a` is positioned so `v` and `w` both come out
+        // large and positive either way: with the naive `u == 0.0` this
+        // reads as an unambiguous hit (no edge function is negative), and
+        // only the f64 recompute reveals `u < 0`, correctly rejecting it.
+        let ray: RayData<f32> =
+            RayData::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0));
+        let a = Vector3::new(-1803269.625, -250680.109375, 5.0);
+        let b = Vector3::new(4609089.5, 6198283.5, 5.0);
+        let c = Vector3::new(-5919540.0, -7960571.5, 5.0);
+
+        assert!(ray.intersect(a, b, c).is_none());
+    }
+
+    #[test]
+    fn cull_mode_rejects_only_the_configured_winding() {
+        let org = Vector3::new(0.0, 0.0, 0.0);
+        let dir = Vector3::new(0.0, 0.0, 1.0);
+        // `back` has negative det for this ray and is rejected by
+        // `CullMode::Back`; `front` is the same triangle with b/c swapped,
+        // giving positive det.
+        let back = [
+            Vector3::new(-1.0, -1.0, 5.0),
+            Vector3::new(1.0, -1.0, 5.0),
+            Vector3::new(0.0, 1.0, 5.0),
+        ];
+        let front = [back[0], back[2], back[1]];
+
+        let unculled = RayData::new(org, dir);
+        assert!(unculled.intersect(back[0], back[1], back[2]).is_some());
+        assert!(unculled.intersect(front[0], front[1], front[2]).is_some());
+
+        let culled = RayData::new_culled(org, dir, CullMode::Back);
+        assert!(culled.intersect(back[0], back[1], back[2]).is_none());
+        assert!(culled.intersect(front[0], front[1], front[2]).is_some());
+    }
+
+    #[test]
+    fn cull_mode_swaps_kx_ky_to_keep_winding_consistent_for_a_negative_kz_ray() {
+        // Same triangle as `cull_mode_rejects_only_the_configured_winding`,
+        // but with `dir[kz] < 0`, so `new_culled`'s `swap(kx, ky)` actually
+        // runs. Flipping the ray's z-component also flips which vertex
+        // order has negative det, so `tri` (not `tri_swapped`, as in the
+        // positive-kz case) is the one `CullMode::Back` now keeps -- if the
+        // swap were missing or wrong, this assertion would flip too.
+        let org = Vector3::new(0.0, 0.0, 10.0);
+        let dir = Vector3::new(0.0, 0.0, -1.0);
+        let tri = [
+            Vector3::new(-1.0, -1.0, 5.0),
+            Vector3::new(1.0, -1.0, 5.0),
+            Vector3::new(0.0, 1.0, 5.0),
+        ];
+        let tri_swapped = [tri[0], tri[2], tri[1]];
+
+        let unculled = RayData::new(org, dir);
+        assert!(unculled.intersect(tri[0], tri[1], tri[2]).is_some());
+        assert!(unculled
+            .intersect(tri_swapped[0], tri_swapped[1], tri_swapped[2])
+            .is_some());
+
+        let culled = RayData::new_culled(org, dir, CullMode::Back);
+        assert!(culled.intersect(tri[0], tri[1], tri[2]).is_some());
+        assert!(culled
+            .intersect(tri_swapped[0], tri_swapped[1], tri_swapped[2])
+            .is_none());
+    }
+
+    #[test]
+    fn intersect_clamped_rejects_hits_outside_tmin_tmax() {
+        let ray = RayData::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0));
+        let (a, b, c) = (
+            Vector3::new(-1.0, -1.0, 5.0),
+            Vector3::new(1.0, -1.0, 5.0),
+            Vector3::new(0.0, 1.0, 5.0),
+        );
+        // The ray hits this triangle at t == 5.0.
+        assert!(ray.intersect_clamped(a, b, c, 4.0, 6.0).is_some());
+        assert!(ray.intersect_clamped(a, b, c, 0.0, 4.0).is_none());
+        assert!(ray.intersect_clamped(a, b, c, 6.0, 10.0).is_none());
+    }
+
+    #[test]
+    fn intersect_motion_only_hits_while_triangle_straddles_the_ray() {
+        let ray = RayData::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0));
+        // Triangle starts centered on the ray (a hit at time 0) and
+        // translates off to the side by time 1 (a miss).
+        let a0 = Vector3::new(-1.0, -1.0, 5.0);
+        let b0 = Vector3::new(1.0, -1.0, 5.0);
+        let c0 = Vector3::new(0.0, 1.0, 5.0);
+        let offset = Vector3::new(10.0, 0.0, 0.0);
+        let (a1, b1, c1) = (a0 + offset, b0 + offset, c0 + offset);
+
+        let (pose0, pose1) = ([a0, b0, c0], [a1, b1, c1]);
+        assert!(ray.intersect_motion(pose0, pose1, 0.0).is_some());
+        assert!(ray.intersect_motion(pose0, pose1, 0.9).is_none());
+        assert!(ray.intersect_motion(pose0, pose1, 1.0).is_none());
+    }
+
+    #[test]
+    fn intersect_sphere_front_hit_and_full_miss() {
+        let ray = RayData::new(Vector3::new(0.0, 0.0, -5.0), Vector3::new(0.0, 0.0, 1.0));
+        assert_eq!(ray.intersect_sphere(Vector3::new(0.0, 0.0, 0.0), 1.0), Some(4.0));
+
+        let miss = RayData::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0));
+        assert_eq!(miss.intersect_sphere(Vector3::new(100.0, 100.0, 100.0), 1.0), None);
+    }
+
+    #[test]
+    fn intersect_sphere_from_inside_returns_the_far_root() {
+        let ray = RayData::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0));
+        assert_eq!(ray.intersect_sphere(Vector3::new(0.0, 0.0, 0.0), 5.0), Some(5.0));
+    }
+
+    #[test]
+    fn intersect_sphere_tangent_ray_grazes_at_one_point() {
+        let ray = RayData::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+        assert_eq!(ray.intersect_sphere(Vector3::new(5.0, 1.0, 0.0), 1.0), Some(5.0));
+    }
+
+    #[test]
+    fn intersect_sphere_solves_the_quadratic_directly_for_non_unit_dir() {
+        // `dir` has length 2, so the geometric shortcut (which assumes a
+        // unit direction) would report the wrong `t`; the direct quadratic
+        // solve gets t == 2.0, landing exactly on the sphere's surface.
+        let ray = RayData::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 2.0));
+        assert_eq!(ray.intersect_sphere(Vector3::new(0.0, 0.0, 5.0), 1.0), Some(2.0));
+    }
+
+    #[test]
+    fn intersect_disk_hit_inside_radius_and_miss_outside_radius() {
+        let center = Vector3::new(0.0, 0.0, 5.0);
+        let normal = Vector3::new(0.0, 0.0, 1.0);
+
+        let hit = RayData::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0));
+        assert_eq!(hit.intersect_disk(center, normal, 2.0), Some(5.0));
+
+        let miss = RayData::new(Vector3::new(3.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0));
+        assert_eq!(miss.intersect_disk(center, normal, 2.0), None);
+    }
+
+    #[test]
+    fn intersect_disk_rejects_a_ray_parallel_to_the_plane() {
+        let ray = RayData::new(Vector3::new(0.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+        let center = Vector3::new(0.0, 0.0, 5.0);
+        let normal = Vector3::new(0.0, 0.0, 1.0);
+
+        assert_eq!(ray.intersect_disk(center, normal, 2.0), None);
+    }
+}